@@ -0,0 +1,178 @@
+//! Network-aware, validated wallet addresses.
+//!
+//! Mirrors rust-bitcoin's address overhaul: an address parsed from untrusted
+//! input is an `Address<Unchecked>` until it has been confirmed to belong to
+//! the expected [`Network`], at which point `require_network` turns it into
+//! an `Address<Checked>` that alone is accepted into a [`crate::TrxData`].
+//!
+//! The network is not taken on faith from the caller: addresses are SS58
+//! encoded, and the network is the address format prefix baked into that
+//! encoding, so `require_network` checks the caller's expectation against
+//! what the address string itself actually encodes.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::{Ss58AddressFormat, Ss58Codec};
+use sp_core::ed25519::Public;
+
+use crate::error::TxError;
+
+/// SS58 address format prefix used for mainnet addresses.
+const MAINNET_PREFIX: u16 = 42;
+/// SS58 address format prefix used for testnet addresses.
+const TESTNET_PREFIX: u16 = 142;
+
+/// The network an address is intended for, derived from the SS58 address
+/// format prefix encoded in the address string itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+impl Network {
+    fn to_ss58_format(self) -> Ss58AddressFormat {
+        let prefix: u16 = match self {
+            Network::Mainnet => MAINNET_PREFIX,
+            Network::Testnet => TESTNET_PREFIX,
+        };
+        prefix.into()
+    }
+
+    fn from_ss58_format(format: Ss58AddressFormat) -> Result<Self, TxError> {
+        match u16::from(format) {
+            MAINNET_PREFIX => Ok(Network::Mainnet),
+            TESTNET_PREFIX => Ok(Network::Testnet),
+            _ => Err(TxError::InvalidAddress),
+        }
+    }
+}
+
+/// Marker state: the address has been parsed but not checked against a network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unchecked;
+
+/// Marker state: the address has been confirmed to belong to its `Network`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checked;
+
+/// A wallet address, tagged at the type level with whether it has been
+/// validated against a specific [`Network`].
+///
+/// Parse untrusted input with [`Address::parse`] to get an
+/// `Address<Unchecked>`, then call [`Address::require_network`] to obtain an
+/// `Address<Checked>` before it can be used in a [`crate::TrxData`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Address<State = Unchecked> {
+    key: Public,
+    network: Network,
+    _state: PhantomData<State>,
+}
+
+impl Address<Unchecked> {
+    /// Parses a raw SS58 address string, deriving its network from the
+    /// format prefix encoded in the string. Does not yet guarantee the
+    /// address matches any particular expected network.
+    pub fn parse(raw: &str) -> Result<Self, TxError> {
+        let (key, format) =
+            Public::from_ss58check_with_version(raw.trim()).map_err(|_| TxError::InvalidAddress)?;
+        let network = Network::from_ss58_format(format)?;
+
+        Ok(Self {
+            key,
+            network,
+            _state: PhantomData,
+        })
+    }
+
+    /// Confirms this address's encoded network is `expected`, producing an
+    /// `Address<Checked>` usable in transaction data.
+    pub fn require_network(self, expected: Network) -> Result<Address<Checked>, TxError> {
+        if self.network != expected {
+            return Err(TxError::NetworkMismatch {
+                expected,
+                found: self.network,
+            });
+        }
+
+        Ok(Address {
+            key: self.key,
+            network: self.network,
+            _state: PhantomData,
+        })
+    }
+}
+
+impl Address<Checked> {
+    /// The underlying public key, ready to be embedded in transaction data.
+    pub fn key(&self) -> Public {
+        self.key
+    }
+
+    /// The network this address has been validated against.
+    pub fn network(&self) -> Network {
+        self.network
+    }
+}
+
+impl Serialize for Address<Checked> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.key.to_ss58check_with_version(self.network.to_ss58_format()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Address<Checked> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        let (key, format) =
+            Public::from_ss58check_with_version(&raw).map_err(serde::de::Error::custom)?;
+        let network = Network::from_ss58_format(format).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            key,
+            network,
+            _state: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::Pair as _;
+
+    fn address_string(network: Network) -> String {
+        let (pair, _) = sp_core::ed25519::Pair::generate();
+        pair.public().to_ss58check_with_version(network.to_ss58_format())
+    }
+
+    #[test]
+    fn require_network_accepts_matching_network() {
+        let raw = address_string(Network::Mainnet);
+        let addr = Address::parse(&raw).unwrap();
+
+        assert!(addr.require_network(Network::Mainnet).is_ok());
+    }
+
+    #[test]
+    fn require_network_rejects_mismatched_network() {
+        let raw = address_string(Network::Testnet);
+        let addr = Address::parse(&raw).unwrap();
+
+        let err = addr.require_network(Network::Mainnet).unwrap_err();
+        assert!(matches!(
+            err,
+            TxError::NetworkMismatch {
+                expected: Network::Mainnet,
+                found: Network::Testnet,
+            }
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+//! Polls the network for a transaction's confirmation status after submission.
+
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep, Instant};
+
+use crate::error::TxError;
+
+/// Confirmation status of a submitted transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// Still waiting to be included in a block
+    Pending,
+    /// Included in a block
+    Confirmed,
+    /// Rejected by the network
+    Rejected,
+}
+
+/// Options controlling how [`wait_for_confirmation`] polls for status.
+#[derive(Debug, Clone, Copy)]
+pub struct WatchOptions {
+    /// How long to wait after the first poll (which is immediate), and the starting point for backoff
+    pub interval: Duration,
+    /// The interval between polls doubles after each `Pending` response, up to this cap
+    pub max_interval: Duration,
+    /// How long to keep polling before giving up
+    pub timeout: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// The confirmation state of a transaction, as last observed by the watcher.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Confirmation {
+    /// Hash of the transaction being watched
+    pub hash: String,
+    /// Current status
+    pub status: Status,
+    /// Block height the transaction landed in, once confirmed
+    pub block_height: Option<u64>,
+}
+
+/// Response shape of the node's transaction status endpoint.
+#[derive(Debug, Deserialize)]
+struct StatusRes {
+    status: Status,
+    block_height: Option<u64>,
+}
+
+/// Polls the node for the confirmation status of `hash`, starting at
+/// `opts.interval` and doubling the wait between polls (up to
+/// `opts.max_interval`) each time the status comes back `Pending`, until it
+/// leaves that state or `opts.timeout` elapses, in which case the last known
+/// `Pending` state is returned.
+pub async fn wait_for_confirmation(
+    hash: &str,
+    client: &Client,
+    opts: WatchOptions,
+) -> Result<Confirmation, TxError> {
+    let deadline = Instant::now() + opts.timeout;
+    let url = format!("https://centichain.org/jrpc/trx/{}/status", hash);
+    let mut interval = opts.interval;
+
+    loop {
+        let res = client.get(&url).send().await?;
+        let status_res: StatusRes = res.json().await?;
+
+        if status_res.status != Status::Pending || Instant::now() >= deadline {
+            return Ok(Confirmation {
+                hash: hash.to_string(),
+                status: status_res.status,
+                block_height: status_res.block_height,
+            });
+        }
+
+        sleep(interval).await;
+        interval = (interval * 2).min(opts.max_interval);
+    }
+}
@@ -0,0 +1,96 @@
+//! Error types for building, signing, and submitting transactions.
+
+use std::fmt;
+
+use crate::address::Network;
+
+/// Errors that can occur while building, signing, or submitting a transaction.
+#[derive(Debug)]
+pub enum TxError {
+    /// An address could not be parsed
+    InvalidAddress,
+    /// An address was valid but tagged for a different network than expected
+    NetworkMismatch {
+        /// The network the address was required to belong to
+        expected: Network,
+        /// The network the address was actually tagged with
+        found: Network,
+    },
+    /// The amount string could not be parsed as a decimal
+    InvalidAmount,
+    /// The available UTXOs do not cover the requested value plus fee
+    InsufficientFunds,
+    /// Signing the transaction failed
+    SigningFailed(String),
+    /// A network request failed
+    Network(reqwest::Error),
+    /// The server response could not be decoded
+    Decode(serde_json::Error),
+    /// The node rejected the transaction
+    Rejected {
+        /// Hash of the rejected transaction
+        hash: String,
+        /// Reason the node gave for the rejection
+        description: String,
+    },
+    /// The transaction's hash does not match its data
+    HashMismatch,
+    /// The signing key does not match `data.from`
+    SignerMismatch,
+    /// The signature does not verify against the transaction hash
+    InvalidSignature,
+}
+
+impl fmt::Display for TxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxError::InvalidAddress => write!(f, "invalid address format"),
+            TxError::NetworkMismatch { expected, found } => write!(
+                f,
+                "address is for {:?} but {:?} was required",
+                found, expected
+            ),
+            TxError::InvalidAmount => write!(f, "invalid amount format"),
+            TxError::InsufficientFunds => {
+                write!(f, "insufficient funds: available UTXOs do not cover value + fee")
+            }
+            TxError::SigningFailed(msg) => write!(f, "signing failed: {}", msg),
+            TxError::Network(e) => write!(f, "network error: {}", e),
+            TxError::Decode(e) => write!(f, "failed to decode response: {}", e),
+            TxError::Rejected { hash, description } => {
+                write!(f, "transaction {} rejected: {}", hash, description)
+            }
+            TxError::HashMismatch => write!(f, "transaction hash does not match its data"),
+            TxError::SignerMismatch => write!(f, "signing key does not match data.from"),
+            TxError::InvalidSignature => write!(f, "signature does not verify against transaction hash"),
+        }
+    }
+}
+
+impl std::error::Error for TxError {}
+
+impl From<reqwest::Error> for TxError {
+    fn from(e: reqwest::Error) -> Self {
+        TxError::Network(e)
+    }
+}
+
+impl From<serde_json::Error> for TxError {
+    fn from(e: serde_json::Error) -> Self {
+        TxError::Decode(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_serde_json_error_wraps_it_as_decode() {
+        let json_err = serde_json::from_str::<serde_json::Value>("not json").unwrap_err();
+
+        let err: TxError = json_err.into();
+
+        assert!(matches!(err, TxError::Decode(_)));
+    }
+}
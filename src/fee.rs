@@ -0,0 +1,95 @@
+//! Fee estimation policies for building transactions.
+
+use reqwest::Client;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::error::TxError;
+
+/// How the fee for a transaction should be computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeePolicy {
+    /// A flat, caller-chosen fee
+    Fixed(Decimal),
+    /// A percentage of the transfer value, e.g. `Decimal::new(1, 2)` for 1%
+    Percentage(Decimal),
+    /// Queries the node for its currently recommended rate
+    Estimated,
+}
+
+impl Default for FeePolicy {
+    /// The network's current flat-1% default, kept for backward compatibility.
+    fn default() -> Self {
+        FeePolicy::Percentage(Decimal::new(1, 2))
+    }
+}
+
+/// Response shape of the node's recommended-fee-rate endpoint.
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct FeeRateRes {
+    #[serde_as(as = "DisplayFromStr")]
+    rate: Decimal,
+}
+
+impl FeePolicy {
+    /// Computes the fee for a transfer of `value`, querying the node for the
+    /// recommended rate when using [`FeePolicy::Estimated`].
+    pub async fn compute(&self, value: Decimal, client: &Client) -> Result<Decimal, TxError> {
+        let fee = match self {
+            FeePolicy::Fixed(fee) => *fee,
+            FeePolicy::Percentage(pct) => value * pct,
+            FeePolicy::Estimated => {
+                let url = "https://centichain.org/jrpc/fee/estimate".to_string();
+                let res = client.get(url).send().await?;
+                let FeeRateRes { rate } = res.json().await?;
+                value * rate
+            }
+        };
+
+        Ok(fee.trunc_with_scale(12))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn fixed_fee_ignores_value() {
+        let client = Client::new();
+
+        let fee = FeePolicy::Fixed(Decimal::from_str("2.5").unwrap())
+            .compute(Decimal::from_str("1000").unwrap(), &client)
+            .await
+            .unwrap();
+
+        assert_eq!(fee, Decimal::from_str("2.5").unwrap());
+    }
+
+    #[tokio::test]
+    async fn percentage_fee_scales_with_value() {
+        let client = Client::new();
+
+        let fee = FeePolicy::Percentage(Decimal::new(1, 2))
+            .compute(Decimal::from_str("200").unwrap(), &client)
+            .await
+            .unwrap();
+
+        assert_eq!(fee, Decimal::from_str("2").unwrap());
+    }
+
+    #[tokio::test]
+    async fn default_policy_matches_the_historical_flat_one_percent_fee() {
+        let client = Client::new();
+
+        let fee = FeePolicy::default()
+            .compute(Decimal::from_str("100").unwrap(), &client)
+            .await
+            .unwrap();
+
+        assert_eq!(fee, Decimal::from_str("1").unwrap());
+    }
+}
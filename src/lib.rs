@@ -3,15 +3,28 @@
 //! This module provides functionality for creating, signing and sending transactions,
 //! managing UTXOs (Unspent Transaction Outputs), and handling wallet operations.
 
+pub mod address;
+pub mod confirm;
+pub mod error;
+pub mod fee;
+pub mod utxo;
+
 use rand::Rng;
 use reqwest::Client;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DisplayFromStr}; 
+use serde_with::{serde_as, DisplayFromStr};
 use sha2::{Digest, Sha256};
-use sp_core::ed25519::{Public, Signature};
+use sp_core::ed25519::{Pair as Ed25519Pair, Public, Signature};
+use sp_core::Pair as _;
 use std::str::FromStr;
 
+pub use address::{Address, Checked, Network, Unchecked};
+pub use confirm::{Confirmation, Status, WatchOptions};
+pub use error::TxError;
+pub use fee::FeePolicy;
+pub use utxo::{fetch_utxos, select_utxos, TxOut, Utxo};
+
 /// Represents a digital signature along with the signer's public key
 /// Used to verify the authenticity of transactions
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -38,16 +51,19 @@ pub struct Transaction {
 }
 
 /// Contains the core data fields of a transaction
+///
+/// Follows a UTXO model: `inputs` are previously unspent outputs being
+/// consumed, and `outputs` are the new outputs created, including any change
+/// returned to the sender. The transaction hash commits to all of them.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TrxData {
-    /// Sender's wallet address
-    pub from: String,
-    /// Recipient's public key
-    pub to: Public,
-    /// Amount to transfer
-    #[serde_as(as = "DisplayFromStr")]
-    pub value: Decimal,
+    /// Sender's network-validated address
+    pub from: Address<Checked>,
+    /// Unspent outputs being consumed to fund this transaction
+    pub inputs: Vec<Utxo>,
+    /// New outputs created by this transaction, including any change
+    pub outputs: Vec<TxOut>,
     /// Transaction fee
     #[serde_as(as = "DisplayFromStr")]
     pub fee: Decimal,
@@ -84,78 +100,303 @@ impl HashMaker {
     }
 }
 
+impl TrxData {
+    /// Builds the unsigned core of a transaction: sender, recipient, amount and fee.
+    ///
+    /// Fetches the sender's spendable UTXOs from the node, selects a minimal set
+    /// covering `value + fee`, and produces a recipient output plus a change
+    /// output back to the sender when the selection overshoots. Apart from
+    /// [`Transaction::broadcast`], this is the only stage that touches the
+    /// network; signing is offline.
+    ///
+    /// # Arguments
+    /// * `wallet` - The sender's wallet address
+    /// * `to` - The recipient's address
+    /// * `value` - The amount to send
+    /// * `client` - HTTP client used to fetch the sender's UTXOs and, for
+    ///   [`FeePolicy::Estimated`], the recommended fee rate
+    /// * `fee_policy` - How to compute the transaction fee
+    /// * `network` - The network both `wallet` and `to` must belong to
+    pub async fn build(
+        wallet: String,
+        to: String,
+        value: String,
+        client: &Client,
+        fee_policy: FeePolicy,
+        network: Network,
+    ) -> Result<Self, TxError> {
+        let wallet = wallet.trim();
+
+        let sender = Address::parse(wallet)?.require_network(network)?;
+        let recipient = Address::parse(to.trim())?.require_network(network)?;
+
+        let decimal_value = Decimal::from_str(&value)
+            .map_err(|_| TxError::InvalidAmount)?
+            .trunc_with_scale(12);
+        if decimal_value <= Decimal::ZERO {
+            return Err(TxError::InvalidAmount);
+        }
+        let fee = fee_policy.compute(decimal_value, client).await?;
+
+        let available = fetch_utxos(wallet, client).await?;
+        let selection = select_utxos(&available, decimal_value, fee)?;
+
+        let mut outputs = vec![TxOut {
+            to: recipient,
+            amount: decimal_value,
+        }];
+        if selection.change > Decimal::ZERO {
+            outputs.push(TxOut {
+                to: sender.clone(),
+                amount: selection.change,
+            });
+        }
+
+        // Random value to prevent transaction replay attacks
+        let salt = rand::thread_rng().gen_range(0..10_000_000);
+
+        Ok(Self {
+            from: sender,
+            inputs: selection.inputs,
+            outputs,
+            fee,
+            salt,
+        })
+    }
+}
+
 impl Transaction {
+    /// Signs previously built transaction data, producing its hash and signature.
+    ///
+    /// Performs no network I/O, so signing can happen on a machine that never
+    /// touches the network (e.g. an air-gapped signer), with broadcasting done
+    /// separately via [`Transaction::broadcast`].
+    ///
+    /// # Arguments
+    /// * `trx_data` - The unsigned transaction data, from [`TrxData::build`]
+    /// * `private` - The sender's private key
+    pub fn sign(trx_data: TrxData, private: &str) -> Result<Self, TxError> {
+        let str_data = serde_json::to_string(&trx_data)?;
+        let hash = HashMaker::generate(&str_data);
+
+        let sign = Sign {
+            signatgure: centichain_keypair::CentichainKey::signing(&private.to_string(), &hash)
+                .map_err(|e| TxError::SigningFailed(e.to_string()))?,
+            key: trx_data.from.key(),
+        };
+
+        Ok(Self {
+            hash,
+            data: trx_data,
+            sign,
+            date: "".to_string(),
+        })
+    }
+
+    /// Broadcasts an already-signed transaction to the Centichain network.
+    ///
+    /// Can be called from a different machine than the one that signed the
+    /// transaction, and re-called to re-broadcast a previously signed transaction.
+    pub async fn broadcast(&self, client: &Client) -> Result<TxRes, TxError> {
+        let url = format!("https://centichain.org/jrpc/trx");
+
+        let res = client.post(url).json(self).send().await?;
+        let response: TxRes = res.json().await?;
+
+        if response.status == "success".to_string() {
+            Ok(response)
+        } else {
+            Err(TxError::Rejected {
+                hash: response.hash,
+                description: response.description,
+            })
+        }
+    }
+
     /// Creates and sends a new transaction to the Centichain network
-    /// 
+    ///
+    /// Thin wrapper over [`TrxData::build`], [`Transaction::sign`] and
+    /// [`Transaction::broadcast`] for the common online, one-shot case.
+    ///
     /// # Arguments
     /// * `wallet` - The sender's wallet address
     /// * `private` - The sender's private key
     /// * `to` - The recipient's address
     /// * `value` - The amount to send
-    /// 
+    /// * `fee_policy` - How to compute the transaction fee; defaults to the
+    ///   current flat 1% behavior when `None`
+    /// * `network` - The network both `wallet` and `to` must belong to
+    ///
     /// # Returns
     /// * `Ok(String)` - Success status
-    /// * `Err(String)` - Error message if the transaction fails
+    /// * `Err(TxError)` - The error that caused the transaction to fail
     pub async fn make_and_send(
         wallet: String,
         private: String,
         to: String,
         value: String,
-    ) -> Result<String, String> {
-        let wallet = wallet.trim();
-        
-        let to = to.trim();
-        match to.parse::<Public>() {
-            Ok(recipent) => {
-                // Generate random salt and calculate transaction values
-                let salt = rand::thread_rng().gen_range(0..10_000_000);
-                let decimal_value = Decimal::from_str(&value).unwrap().trunc_with_scale(12);
-                let fee = decimal_value * Decimal::from_str("0.01").unwrap().trunc_with_scale(12);
-
-                // Create transaction data structure
-                let trx_data = TrxData {
-                    from: wallet.to_string(),
-                    to: recipent,
-                    value: decimal_value,
-                    fee: fee.trunc_with_scale(12),
-                    salt,
-                };
-
-                // Generate transaction hash and sign it
-                let str_data = serde_json::to_string(&trx_data).unwrap();
-                let hash = HashMaker::generate(&str_data);
-                let sign = Sign {
-                    signatgure: centichain_keypair::CentichainKey::signing(&private, &hash)
-                        .unwrap(),
-                    key: wallet.parse().unwrap(),
-                };
-
-                // Create final transaction object
-                let transaction = Self {
-                    hash,
-                    data: trx_data,
-                    sign,
-                    date: "".to_string(),
-                };
-
-                // Send transaction to the network
-                let client = Client::new();
-                let url = format!("https://centichain.org/jrpc/trx");
-
-                match client.post(url).json(&transaction).send().await {
-                    Ok(res) => {
-                        let response: TxRes = res.json().await.unwrap();
-
-                        if response.status == "success".to_string() {
-                            return Ok(response.status);
-                        } else {
-                            return Err(response.description);
-                        }
-                    }
-                    Err(e) => return Err(e.to_string()),
-                }
-            }
-            Err(_) => return Err("Invalid recipient address format".to_string()),
+        fee_policy: Option<FeePolicy>,
+        network: Network,
+    ) -> Result<String, TxError> {
+        let client = Client::new();
+        let trx_data = TrxData::build(
+            wallet,
+            to,
+            value,
+            &client,
+            fee_policy.unwrap_or_default(),
+            network,
+        )
+        .await?;
+        let transaction = Self::sign(trx_data, &private)?;
+
+        let response = transaction.broadcast(&client).await?;
+
+        Ok(response.status)
+    }
+
+    /// Polls the node for the confirmation status of a submitted transaction.
+    ///
+    /// Watches `hash`, starting at `opts.interval` and backing off (doubling
+    /// up to `opts.max_interval`) between polls, until it is confirmed or
+    /// rejected, or returns the last observed `Pending` state once
+    /// `opts.timeout` elapses.
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the previously broadcast transaction
+    /// * `client` - HTTP client used to poll the status endpoint
+    /// * `opts` - Starting poll interval, backoff cap, and overall timeout
+    pub async fn wait_for_confirmation(
+        hash: &str,
+        client: &Client,
+        opts: WatchOptions,
+    ) -> Result<Confirmation, TxError> {
+        confirm::wait_for_confirmation(hash, client, opts).await
+    }
+
+    /// Verifies an inbound transaction: that its hash matches its data, that
+    /// its signature is valid for that hash, and that the signing key matches
+    /// `data.from`.
+    ///
+    /// Lets a node, explorer, or other receiving party validate a transaction
+    /// it did not itself produce.
+    pub fn verify(&self) -> Result<(), TxError> {
+        let str_data = serde_json::to_string(&self.data)?;
+        let expected_hash = HashMaker::generate(&str_data);
+        if expected_hash != self.hash {
+            return Err(TxError::HashMismatch);
+        }
+
+        if self.data.from.key() != self.sign.key {
+            return Err(TxError::SignerMismatch);
+        }
+
+        if !Ed25519Pair::verify(&self.sign.signatgure, self.hash.as_bytes(), &self.sign.key) {
+            return Err(TxError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::Ss58Codec;
+
+    fn checked_address(network: Network) -> Address<Checked> {
+        let (pair, _) = Ed25519Pair::generate();
+        let raw = pair.public().to_ss58check_with_version(42u16.into());
+        Address::parse(&raw).unwrap().require_network(network).unwrap()
+    }
+
+    fn sample_trx_data() -> TrxData {
+        let addr = checked_address(Network::Mainnet);
+        TrxData {
+            from: addr.clone(),
+            inputs: vec![],
+            outputs: vec![TxOut {
+                to: addr,
+                amount: Decimal::from_str("1").unwrap(),
+            }],
+            fee: Decimal::ZERO,
+            salt: 0,
         }
     }
+
+    #[test]
+    fn sign_reports_signing_failure_for_an_invalid_private_key() {
+        let trx_data = sample_trx_data();
+
+        let err = Transaction::sign(trx_data, "not a valid seed phrase").unwrap_err();
+
+        assert!(matches!(err, TxError::SigningFailed(_)));
+    }
+
+    /// Builds a transaction signed directly with `sp_core`, bypassing
+    /// `centichain_keypair`, since `verify` only checks the signature against
+    /// the key and hash (and the key against `data.from`), not how it was
+    /// produced.
+    fn sample_transaction() -> (Transaction, Ed25519Pair) {
+        let (pair, _) = Ed25519Pair::generate();
+        let mut trx_data = sample_trx_data();
+        trx_data.from = Address::parse(&pair.public().to_ss58check_with_version(42u16.into()))
+            .unwrap()
+            .require_network(Network::Mainnet)
+            .unwrap();
+        let str_data = serde_json::to_string(&trx_data).unwrap();
+        let hash = HashMaker::generate(&str_data);
+
+        let signature = pair.sign(hash.as_bytes());
+
+        let transaction = Transaction {
+            hash,
+            data: trx_data,
+            sign: Sign {
+                signatgure: signature,
+                key: pair.public(),
+            },
+            date: "".to_string(),
+        };
+
+        (transaction, pair)
+    }
+
+    #[test]
+    fn verify_accepts_a_correctly_signed_transaction() {
+        let (transaction, _) = sample_transaction();
+
+        assert!(transaction.verify().is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_hash() {
+        let (mut transaction, _) = sample_transaction();
+        transaction.hash = "tampered-hash".to_string();
+
+        let err = transaction.verify().unwrap_err();
+
+        assert!(matches!(err, TxError::HashMismatch));
+    }
+
+    #[test]
+    fn verify_rejects_a_mismatched_signer() {
+        let (mut transaction, _) = sample_transaction();
+        let (other_pair, _) = Ed25519Pair::generate();
+        transaction.sign.key = other_pair.public();
+
+        let err = transaction.verify().unwrap_err();
+
+        assert!(matches!(err, TxError::SignerMismatch));
+    }
+
+    #[test]
+    fn verify_rejects_an_invalid_signature() {
+        let (mut transaction, pair) = sample_transaction();
+        transaction.sign.signatgure = pair.sign(b"a different message");
+
+        let err = transaction.verify().unwrap_err();
+
+        assert!(matches!(err, TxError::InvalidSignature));
+    }
 }
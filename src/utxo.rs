@@ -0,0 +1,132 @@
+//! UTXO (Unspent Transaction Output) model and coin selection.
+//!
+//! Mirrors Bitcoin's `TxIn`/`TxOut` split: a [`Utxo`] is a spendable output of
+//! some earlier transaction, and a [`TxOut`] is a new output being created by
+//! the transaction under construction.
+
+use reqwest::Client;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::address::{Address, Checked};
+use crate::error::TxError;
+
+/// A spendable output of a previous transaction.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Utxo {
+    /// Hash of the transaction that created this output
+    pub trx_hash: String,
+    /// Index of this output within that transaction
+    pub index: u32,
+    /// Amount held by this output
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: Decimal,
+}
+
+/// A new output being created by the transaction under construction.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TxOut {
+    /// Recipient's network-validated address
+    pub to: Address<Checked>,
+    /// Amount sent to this output
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: Decimal,
+}
+
+/// The result of selecting UTXOs to cover a target amount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection {
+    /// The UTXOs chosen to fund the transaction
+    pub inputs: Vec<Utxo>,
+    /// Amount left over after `value + fee`, to be returned to the sender
+    pub change: Decimal,
+}
+
+/// Queries the node for the spendable UTXOs belonging to `wallet`.
+pub async fn fetch_utxos(wallet: &str, client: &Client) -> Result<Vec<Utxo>, TxError> {
+    let url = format!("https://centichain.org/jrpc/utxos/{}", wallet);
+
+    let res = client.get(url).send().await?;
+    Ok(res.json::<Vec<Utxo>>().await?)
+}
+
+/// Greedily selects the smallest set of UTXOs (largest-first) whose total
+/// covers `value + fee`, reporting the leftover as change.
+pub fn select_utxos(utxos: &[Utxo], value: Decimal, fee: Decimal) -> Result<Selection, TxError> {
+    let target = value + fee;
+
+    let mut sorted: Vec<&Utxo> = utxos.iter().collect();
+    sorted.sort_by_key(|utxo| std::cmp::Reverse(utxo.amount));
+
+    let mut inputs = Vec::new();
+    let mut total = Decimal::ZERO;
+
+    for utxo in sorted {
+        if total >= target {
+            break;
+        }
+        total += utxo.amount;
+        inputs.push(utxo.clone());
+    }
+
+    if total < target {
+        return Err(TxError::InsufficientFunds);
+    }
+
+    Ok(Selection {
+        inputs,
+        change: total - target,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn utxo(amount: &str) -> Utxo {
+        Utxo {
+            trx_hash: "hash".to_string(),
+            index: 0,
+            amount: Decimal::from_str(amount).unwrap(),
+        }
+    }
+
+    #[test]
+    fn selects_minimal_utxos_largest_first_and_reports_change() {
+        let utxos = vec![utxo("5"), utxo("3"), utxo("1")];
+
+        let selection =
+            select_utxos(&utxos, Decimal::from_str("4").unwrap(), Decimal::from_str("1").unwrap())
+                .unwrap();
+
+        assert_eq!(selection.inputs, vec![utxo("5")]);
+        assert_eq!(selection.change, Decimal::from_str("0").unwrap());
+    }
+
+    #[test]
+    fn selects_multiple_utxos_when_one_is_not_enough() {
+        let utxos = vec![utxo("3"), utxo("2")];
+
+        let selection =
+            select_utxos(&utxos, Decimal::from_str("4").unwrap(), Decimal::from_str("0").unwrap())
+                .unwrap();
+
+        assert_eq!(selection.inputs, vec![utxo("3"), utxo("2")]);
+        assert_eq!(selection.change, Decimal::from_str("1").unwrap());
+    }
+
+    #[test]
+    fn errors_when_available_utxos_do_not_cover_value_plus_fee() {
+        let utxos = vec![utxo("1")];
+
+        let err =
+            select_utxos(&utxos, Decimal::from_str("5").unwrap(), Decimal::from_str("0").unwrap())
+                .unwrap_err();
+
+        assert!(matches!(err, TxError::InsufficientFunds));
+    }
+}